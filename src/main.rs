@@ -1,9 +1,9 @@
 use actix_web::{web, App, HttpServer, HttpResponse, Responder, error};
-use mysql::{Pool, PooledConn, prelude::Queryable};
+use mysql_async::{Pool, Conn, prelude::Queryable};
 use serde::{Deserialize, Serialize};
-use std::sync::Mutex;
 use std::collections::HashMap;
 use thiserror::Error;
+use futures::stream::{self, StreamExt};
 
 // Import serde_json with macro_use to enable the json! macro
 #[macro_use]
@@ -23,13 +23,16 @@ mod config {
         pub mysql_password: String,
         pub mysql_db: String,
         pub app_port: u16,
+        pub stmt_cache_capacity: usize,
+        pub db_retry_delay_secs: u64,
+        pub db_connect_timeout_secs: u64,
     }
 
     impl Settings {
         pub fn new() -> Result<Self, config::ConfigError> {
             // Load environment variables from .env file if it exists
             dotenv().ok();
-            
+
             let mysql_host = env::var("MYSQL_HOST").unwrap_or_else(|_| "localhost".to_string());
             let mysql_port = env::var("MYSQL_PORT").unwrap_or_else(|_| "3306".to_string())
                 .parse::<u16>().unwrap_or(3306);
@@ -38,7 +41,13 @@ mod config {
             let mysql_db = env::var("MYSQL_DB").expect("MYSQL_DB must be set");
             let app_port = env::var("APP_PORT").unwrap_or_else(|_| "8080".to_string())
                 .parse::<u16>().unwrap_or(8080);
-            
+            let stmt_cache_capacity = env::var("STMT_CACHE_CAPACITY").unwrap_or_else(|_| "256".to_string())
+                .parse::<usize>().unwrap_or(256);
+            let db_retry_delay_secs = env::var("DB_RETRY_DELAY_SECS").unwrap_or_else(|_| "5".to_string())
+                .parse::<u64>().unwrap_or(5);
+            let db_connect_timeout_secs = env::var("DB_CONNECT_TIMEOUT_SECS").unwrap_or_else(|_| "300".to_string())
+                .parse::<u64>().unwrap_or(300);
+
             Ok(Settings {
                 mysql_host,
                 mysql_port,
@@ -46,28 +55,39 @@ mod config {
                 mysql_password,
                 mysql_db,
                 app_port,
+                stmt_cache_capacity,
+                db_retry_delay_secs,
+                db_connect_timeout_secs,
             })
         }
     }
 }
 
-// Define application state - the database connection pool
+// Define application state - the database connection pool.
+// mysql_async::Pool is internally an Arc'd handle, so it can be cloned/shared
+// across workers without a Mutex; checkout is async and non-blocking.
+//
+// Prepared statements are cached by mysql_async itself, per connection (see
+// `stmt_cache_size` on the `Pool`'s `OptsBuilder` in `main`); a `Statement`
+// handle is only valid on the connection that prepared it, so caching one
+// here at the app level would hand out statements against the wrong
+// connection as soon as the pool recycled or load-balanced a checkout.
 struct AppState {
-    db_pool: Mutex<Pool>,
+    db_pool: Pool,
 }
 
 // Custom error type for our application
 #[derive(Error, Debug)]
 enum AppError {
     #[error("Database error: {0}")]
-    DbError(#[from] mysql::Error),
-    
+    DbError(#[from] mysql_async::Error),
+
     #[error("Not found: {0}")]
     NotFound(String),
-    
+
     #[error("Bad request: {0}")]
     BadRequest(String),
-    
+
     #[error("Internal server error: {0}")]
     InternalError(String),
 }
@@ -106,13 +126,240 @@ impl error::ResponseError for AppError {
     }
 }
 
-// Helper function to get a database connection from the pool
-fn get_conn(state: &AppState) -> Result<PooledConn, AppError> {
-    let pool = state.db_pool.lock().map_err(|e| {
-        AppError::InternalError(format!("Failed to acquire DB lock: {}", e))
-    })?;
-    
-    pool.get_conn().map_err(AppError::DbError)
+// Checks out a connection from the pool, retrying on failure with a fixed
+// delay until `connect_timeout_secs` has elapsed. Used only by `main`'s
+// startup check, where waiting out a MySQL server that's still coming up is
+// desirable; request handlers use `get_conn` instead, which fails fast so a
+// real outage surfaces as a prompt error rather than a hung request.
+async fn acquire_conn(pool: &Pool, retry_delay_secs: u64, connect_timeout_secs: u64) -> Result<Conn, mysql_async::Error> {
+    let delay = std::time::Duration::from_secs(retry_delay_secs);
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(connect_timeout_secs);
+
+    loop {
+        match pool.get_conn().await {
+            Ok(conn) => return Ok(conn),
+            Err(err) => {
+                if tokio::time::Instant::now() >= deadline {
+                    return Err(err);
+                }
+                log::warn!(
+                    "Database connection attempt failed, retrying in {}s: {}",
+                    delay.as_secs(), err
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+// Helper function to check out a connection from the async pool. A single
+// non-retrying attempt, so a database outage fails a request immediately
+// instead of holding it open for up to `db_connect_timeout_secs`.
+async fn get_conn(state: &AppState) -> Result<Conn, AppError> {
+    state.db_pool.get_conn().await.map_err(AppError::DbError)
+}
+
+// Lightweight readiness probe: attempts `SELECT 1` through the pool and
+// reports 200 when the database is reachable, 503 otherwise, so
+// orchestrators can gate traffic on real connectivity rather than just
+// process liveness.
+async fn health_check(data: web::Data<AppState>) -> impl Responder {
+    match data.db_pool.get_conn().await {
+        Ok(mut conn) => match conn.query_drop("SELECT 1").await {
+            Ok(()) => HttpResponse::Ok().json(json!({"status": "ok"})),
+            Err(err) => {
+                log::error!("Health check query failed: {:?}", err);
+                HttpResponse::ServiceUnavailable().json(json!({"status": "unavailable"}))
+            }
+        },
+        Err(err) => {
+            log::error!("Health check connection failed: {:?}", err);
+            HttpResponse::ServiceUnavailable().json(json!({"status": "unavailable"}))
+        }
+    }
+}
+
+// Converts a single `mysql_async::Value` cell into the matching JSON
+// representation. This is the one place NULL/Bytes/Int/UInt/Float/Date/Time
+// conversion lives, so every handler agrees on how e.g. an out-of-range
+// UInt or a non-finite Float degrades.
+fn json_value_at(row: &mysql_async::Row, i: usize) -> serde_json::Value {
+    match row.get_opt::<mysql_async::Value, _>(i) {
+        Some(Ok(mysql_async::Value::NULL)) => serde_json::Value::Null,
+        Some(Ok(mysql_async::Value::Bytes(bytes))) => {
+            if let Ok(s) = String::from_utf8(bytes.clone()) {
+                serde_json::Value::String(s)
+            } else {
+                serde_json::Value::Array(
+                    bytes.into_iter()
+                        .map(|b| serde_json::Value::Number(b.into()))
+                        .collect()
+                )
+            }
+        },
+        Some(Ok(mysql_async::Value::Int(i))) => serde_json::Value::Number(i.into()),
+        Some(Ok(mysql_async::Value::UInt(i))) => {
+            if let Some(num) = serde_json::Number::from_u128(i as u128) {
+                serde_json::Value::Number(num)
+            } else {
+                serde_json::Value::String(i.to_string())
+            }
+        },
+        Some(Ok(mysql_async::Value::Float(f))) => {
+            if let Some(num) = serde_json::Number::from_f64(f.into()) {
+                serde_json::Value::Number(num)
+            } else {
+                serde_json::Value::String(f.to_string())
+            }
+        },
+        Some(Ok(mysql_async::Value::Date(..))) |
+        Some(Ok(mysql_async::Value::Time(..))) => {
+            let s: Option<String> = row.get(i);
+            serde_json::Value::String(s.unwrap_or_default())
+        },
+        _ => serde_json::Value::Null,
+    }
+}
+
+// Converts a raw `mysql_async::Row` into a typed value, centralizing the
+// row-shape conversions handlers otherwise hand-rolled inline. Each handler
+// asks for the shape it needs (a single string column, a single count
+// column, or a full column-name -> JSON map) instead of matching on
+// `mysql_async::Value` itself.
+trait FromRow: Sized {
+    fn from_row(row: &mysql_async::Row) -> Result<Self, AppError>;
+}
+
+impl FromRow for (String,) {
+    fn from_row(row: &mysql_async::Row) -> Result<Self, AppError> {
+        let value: Option<String> = row.get(0);
+        Ok((value.unwrap_or_default(),))
+    }
+}
+
+impl FromRow for (u64,) {
+    fn from_row(row: &mysql_async::Row) -> Result<Self, AppError> {
+        let value: Option<u64> = row.get(0);
+        Ok((value.unwrap_or(0),))
+    }
+}
+
+impl FromRow for HashMap<String, serde_json::Value> {
+    fn from_row(row: &mysql_async::Row) -> Result<Self, AppError> {
+        let mut map = HashMap::new();
+        for (i, column) in row.columns_ref().iter().enumerate() {
+            map.insert(column.name_str().to_string(), json_value_at(row, i));
+        }
+        Ok(map)
+    }
+}
+
+// `SHOW COLUMNS`/`SHOW TABLES`-style rows, flattened to plain strings rather
+// than typed JSON. Built on `json_value_at` so NULL/Bytes/Int/UInt/Float
+// conversion stays in one place; a JSON null becomes an empty string and
+// anything else is rendered via its `Display`/JSON text form.
+impl FromRow for HashMap<String, String> {
+    fn from_row(row: &mysql_async::Row) -> Result<Self, AppError> {
+        let mut map = HashMap::new();
+        for (i, column) in row.columns_ref().iter().enumerate() {
+            let value = match json_value_at(row, i) {
+                serde_json::Value::Null => String::new(),
+                serde_json::Value::String(s) => s,
+                other => other.to_string(),
+            };
+            map.insert(column.name_str().to_string(), value);
+        }
+        Ok(map)
+    }
+}
+
+fn rows_to<T: FromRow>(rows: Vec<mysql_async::Row>) -> Result<Vec<T>, AppError> {
+    rows.iter().map(T::from_row).collect()
+}
+
+// Identifier validation. Table/column/field names arrive as path or query
+// parameters and get interpolated into SQL as backtick-quoted identifiers
+// (MySQL has no placeholder syntax for identifiers). Validating them on
+// construction means a raw, unvalidated `String` can never reach a query
+// string, closing off backtick/quote escapes.
+const MAX_IDENTIFIER_LEN: usize = 64;
+
+#[derive(Debug, Clone)]
+struct Identifier(String);
+
+impl Identifier {
+    fn parse(raw: &str) -> Result<Self, AppError> {
+        if raw.is_empty() || raw.len() > MAX_IDENTIFIER_LEN {
+            return Err(AppError::BadRequest(format!(
+                "Invalid identifier '{}': must be 1-{} characters",
+                raw, MAX_IDENTIFIER_LEN
+            )));
+        }
+
+        if !raw.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+            return Err(AppError::BadRequest(format!(
+                "Invalid identifier '{}': only letters, digits, '_' and '$' are allowed",
+                raw
+            )));
+        }
+
+        Ok(Identifier(raw.to_string()))
+    }
+
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// Quotes an already-validated identifier for interpolation into SQL.
+fn quote_ident(ident: &Identifier) -> String {
+    format!("`{}`", ident.as_str())
+}
+
+#[derive(Debug, Clone)]
+struct TableName(Identifier);
+
+impl TableName {
+    fn parse(raw: &str) -> Result<Self, AppError> {
+        Identifier::parse(raw).map(TableName)
+    }
+
+    fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ColumnName(Identifier);
+
+impl ColumnName {
+    fn parse(raw: &str) -> Result<Self, AppError> {
+        Identifier::parse(raw).map(ColumnName)
+    }
+
+    fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+// Parameterized existence checks against information_schema, replacing the
+// old `SHOW ... LIKE '{}'` string interpolation.
+async fn table_exists(conn: &mut Conn, table: &TableName) -> Result<bool, AppError> {
+    let rows: Vec<mysql_async::Row> = conn.exec(
+        "SELECT 1 FROM information_schema.tables WHERE table_schema = DATABASE() AND table_name = ? LIMIT 1",
+        (table.as_str(),),
+    ).await.map_err(AppError::DbError)?;
+
+    Ok(!rows.is_empty())
+}
+
+async fn column_exists(conn: &mut Conn, table: &TableName, column: &ColumnName) -> Result<bool, AppError> {
+    let rows: Vec<mysql_async::Row> = conn.exec(
+        "SELECT 1 FROM information_schema.columns WHERE table_schema = DATABASE() AND table_name = ? AND column_name = ? LIMIT 1",
+        (table.as_str(), column.as_str()),
+    ).await.map_err(AppError::DbError)?;
+
+    Ok(!rows.is_empty())
 }
 
 // Macro to create our serializable response types
@@ -153,37 +400,90 @@ define_response!(QueryResponse {
     value: String,
     columns: serde_json::Value, // Can be a string "all" or a Vec<String>
     limit: u32,
+    offset: u32,
+    total_count: u64,
     results: Vec<HashMap<String, serde_json::Value>>,
 });
 
+// A single table/column hit from a cross-table search
+#[derive(Serialize)]
+struct SearchMatch {
+    table: String,
+    column: String,
+    matches: Vec<HashMap<String, serde_json::Value>>,
+}
+
+define_response!(SearchResponse {
+    value: String,
+    results: Vec<SearchMatch>,
+});
+
 // Request parameters
 #[derive(Deserialize)]
 struct ColumnValuesParams {
     limit: Option<u32>,
 }
 
+#[derive(Deserialize)]
+struct SearchParams {
+    value: String,
+    limit: Option<u32>,
+}
+
 #[derive(Deserialize)]
 struct QueryParams {
     field: Option<String>,
     value: Option<String>,
     columns: Option<String>,
     limit: Option<u32>,
+    op: Option<String>,
+    offset: Option<u32>,
+    order_by: Option<String>,
+    order_dir: Option<String>,
+}
+
+// Whitelist mapping a user-facing `op` value to its SQL operator. The SQL
+// side is always one of these literals, never interpolated from user text.
+const COMPARISON_OPERATORS: &[(&str, &str)] = &[
+    ("eq", "="),
+    ("ne", "!="),
+    ("lt", "<"),
+    ("lte", "<="),
+    ("gt", ">"),
+    ("gte", ">="),
+    ("like", "LIKE"),
+];
+
+fn resolve_operator(op: &str) -> Result<&'static str, AppError> {
+    COMPARISON_OPERATORS.iter()
+        .find(|(name, _)| *name == op)
+        .map(|(_, sql)| *sql)
+        .ok_or_else(|| AppError::BadRequest(format!(
+            "Invalid 'op' value '{}': expected one of {:?}",
+            op, COMPARISON_OPERATORS.iter().map(|(name, _)| *name).collect::<Vec<_>>()
+        )))
+}
+
+// Validates an `order_dir` value against the closed ASC/DESC set.
+fn resolve_order_dir(dir: &str) -> Result<&'static str, AppError> {
+    match dir.to_ascii_uppercase().as_str() {
+        "ASC" => Ok("ASC"),
+        "DESC" => Ok("DESC"),
+        other => Err(AppError::BadRequest(format!(
+            "Invalid 'order_dir' value '{}': expected 'asc' or 'desc'", other
+        ))),
+    }
 }
 
 // Route handlers
 async fn list_tables(data: web::Data<AppState>) -> Result<impl Responder, AppError> {
-    let mut conn = get_conn(&data)?;
-    
+    let mut conn = get_conn(&data).await?;
+
     // Execute query to show tables
-    let tables: Vec<String> = conn.query("SHOW TABLES")
-        .map_err(AppError::DbError)?
-        .into_iter()
-        .map(|row: mysql::Row| {
-            let value: String = mysql::from_row(row);
-            value
-        })
-        .collect();
-    
+    let rows: Vec<mysql_async::Row> = conn.query("SHOW TABLES").await
+        .map_err(AppError::DbError)?;
+    let tables = rows_to::<(String,)>(rows)?.into_iter().map(|(t,)| t).collect();
+
     Ok(HttpResponse::Ok().json(TablesResponse { tables }))
 }
 
@@ -191,40 +491,17 @@ async fn table_columns(
     data: web::Data<AppState>,
     path: web::Path<String>,
 ) -> Result<impl Responder, AppError> {
-    let table = path.into_inner();
-    let mut conn = get_conn(&data)?;
-    
+    let table = TableName::parse(&path.into_inner())?;
+    let mut conn = get_conn(&data).await?;
+
     // Execute query to show columns for the table
-    let query = format!("SHOW COLUMNS FROM `{}`", table);
-    let result = conn.query(query);
-    
+    let query = format!("SHOW COLUMNS FROM {}", quote_ident(&table.0));
+    let result = conn.query(query).await;
+    let table = table.as_str().to_string();
+
     match result {
         Ok(rows) => {
-            let columns = rows.into_iter().map(|row: mysql::Row| {
-                // Convert the row to a HashMap<String, String>
-                let mut column_map = HashMap::new();
-                for (i, column) in row.columns_ref().iter().enumerate() {
-                    let column_name = column.name_str().to_string();
-                    // Get the value safely, converting to string if possible, or empty string if null
-                    let value = match row.get_opt::<mysql::Value, _>(i) {
-
-                        Some(Ok(mysql::Value::NULL)) => String::new(),
-                        Some(Ok(mysql::Value::Bytes(bytes))) => String::from_utf8_lossy(&bytes).to_string(),
-                        Some(Ok(mysql::Value::Int(i))) => i.to_string(),
-                        Some(Ok(mysql::Value::UInt(i))) => i.to_string(),
-                        Some(Ok(mysql::Value::Float(f))) => f.to_string(),
-                        Some(Ok(mysql::Value::Date(..))) |
-                        Some(Ok(mysql::Value::Time(..))) => {
-                            let s: Option<String> = row.get(i);
-                            s.unwrap_or_default()
-                        },
-                        _ => String::new(),
-                    };
-                    column_map.insert(column_name, value);
-                }
-                column_map
-            }).collect();
-            
+            let columns = rows_to::<HashMap<String, String>>(rows)?;
             Ok(HttpResponse::Ok().json(TableColumnsResponse { table, columns }))
         },
         Err(_) => {
@@ -238,38 +515,33 @@ async fn column_distinct_values(
     path: web::Path<(String, String)>,
     query: web::Query<ColumnValuesParams>,
 ) -> Result<impl Responder, AppError> {
-    let (table, column) = path.into_inner();
+    let (table_raw, column_raw) = path.into_inner();
+    let table = TableName::parse(&table_raw)?;
+    let column = ColumnName::parse(&column_raw)?;
     let limit = std::cmp::min(query.limit.unwrap_or(20), 1000) as u32;
-    let mut conn = get_conn(&data)?;
-    
+    let mut conn = get_conn(&data).await?;
+
     // First check if the column exists
-    let column_check_query = format!("SHOW COLUMNS FROM `{}` LIKE '{}'", table, column);
-    let columns: Vec<mysql::Row> = conn.query(column_check_query)
-        .map_err(|_| AppError::BadRequest(format!("Column '{}' not found in table '{}'", column, table)))?;
-    
-    if columns.is_empty() {
-        return Err(AppError::BadRequest(format!("Column '{}' not found in table '{}'", column, table)));
-    }
-    
+    if !column_exists(&mut conn, &table, &column).await? {
+        return Err(AppError::BadRequest(format!(
+            "Column '{}' not found in table '{}'", column.as_str(), table.as_str()
+        )));
+    }
+
     // Get distinct values from the column
     let query = format!(
-        "SELECT DISTINCT `{}` AS value FROM `{}` WHERE `{}` IS NOT NULL LIMIT {}",
-        column, table, column, limit
+        "SELECT DISTINCT {col} AS value FROM {tbl} WHERE {col} IS NOT NULL LIMIT {limit}",
+        col = quote_ident(&column.0), tbl = quote_ident(&table.0), limit = limit
     );
-    
-    let rows: Vec<mysql::Row> = conn.query(query)
+
+    let rows: Vec<mysql_async::Row> = conn.query(query).await
         .map_err(AppError::DbError)?;
-    
-    let values: Vec<String> = rows.into_iter()
-        .map(|row: mysql::Row| {
-            let value: Option<String> = row.get(0);
-            value.unwrap_or_default()
-        })
-        .collect();
-    
+
+    let values = rows_to::<(String,)>(rows)?.into_iter().map(|(v,)| v).collect();
+
     Ok(HttpResponse::Ok().json(ColumnValuesResponse {
-        table,
-        column,
+        table: table.as_str().to_string(),
+        column: column.as_str().to_string(),
         distinct_values: values,
         limit,
     }))
@@ -279,27 +551,22 @@ async fn table_row_count(
     data: web::Data<AppState>,
     path: web::Path<String>,
 ) -> Result<impl Responder, AppError> {
-    let table = path.into_inner();
-    let mut conn = get_conn(&data)?;
-    
+    let table = TableName::parse(&path.into_inner())?;
+    let mut conn = get_conn(&data).await?;
+
     // First check if the table exists
-    let table_check_query = format!("SHOW TABLES LIKE '{}'", table);
-    let tables: Vec<mysql::Row> = conn.query(table_check_query)
-        .map_err(AppError::DbError)?;
-    
-    if tables.is_empty() {
-        return Err(AppError::BadRequest(format!("Table '{}' does not exist", table)));
+    if !table_exists(&mut conn, &table).await? {
+        return Err(AppError::BadRequest(format!("Table '{}' does not exist", table.as_str())));
     }
-    
+
     // Get the row count
-    let query = format!("SELECT COUNT(*) AS count FROM `{}`", table);
-    let result: Vec<(u64,)> = conn.query(query)
+    let query = format!("SELECT COUNT(*) AS count FROM {}", quote_ident(&table.0));
+    let rows: Vec<mysql_async::Row> = conn.query(query).await
         .map_err(AppError::DbError)?;
-    
-    let count = result.first().map(|r| r.0).unwrap_or(0);
-    
+    let count = rows_to::<(u64,)>(rows)?.first().map(|r| r.0).unwrap_or(0);
+
     Ok(HttpResponse::Ok().json(TableCountResponse {
-        table,
+        table: table.as_str().to_string(),
         total_count: count,
     }))
 }
@@ -309,193 +576,339 @@ async fn query_table(
     path: web::Path<String>,
     query: web::Query<QueryParams>,
 ) -> Result<impl Responder, AppError> {
-    let table = path.into_inner();
-    let field = query.field.clone().ok_or_else(|| 
+    let table = TableName::parse(&path.into_inner())?;
+    let field = query.field.clone().ok_or_else(||
         AppError::BadRequest("Please provide 'field' query parameter".to_string()))?;
-    
-    let value = query.value.clone().ok_or_else(|| 
+    let field = ColumnName::parse(&field)?;
+
+    let value = query.value.clone().ok_or_else(||
         AppError::BadRequest("Please provide 'value' query parameter".to_string()))?;
-    
+
     let limit = std::cmp::min(query.limit.unwrap_or(20), 1000);
-    let mut conn = get_conn(&data)?;
-    
+    let offset = query.offset.unwrap_or(0);
+    let operator = resolve_operator(query.op.as_deref().unwrap_or("eq"))?;
+    let mut conn = get_conn(&data).await?;
+
     // Check if the field exists
-    let field_check_query = format!("SHOW COLUMNS FROM `{}` LIKE '{}'", table, field);
-    let fields: Vec<mysql::Row> = conn.query(field_check_query)
-        .map_err(|_| AppError::BadRequest(format!("Field '{}' not found in table '{}'", field, table)))?;
-    
-    if fields.is_empty() {
-        return Err(AppError::BadRequest(format!("Field '{}' not found in table '{}'", field, table)));
-    }
-    
+    if !column_exists(&mut conn, &table, &field).await? {
+        return Err(AppError::BadRequest(format!(
+            "Field '{}' not found in table '{}'", field.as_str(), table.as_str()
+        )));
+    }
+
+    // Validate ordering, if requested, against the table's real columns
+    let order_clause = match &query.order_by {
+        Some(order_by) => {
+            let order_col = ColumnName::parse(order_by)?;
+            if !column_exists(&mut conn, &table, &order_col).await? {
+                return Err(AppError::BadRequest(format!(
+                    "Cannot order by unknown column '{}'", order_col.as_str()
+                )));
+            }
+            let dir = resolve_order_dir(query.order_dir.as_deref().unwrap_or("ASC"))?;
+            format!(" ORDER BY {} {}", quote_ident(&order_col.0), dir)
+        },
+        None => String::new(),
+    };
+
     // Handle requested columns
     let columns_json;
     let columns_sql;
-    
+
     if let Some(columns) = &query.columns {
-        let requested_cols: Vec<String> = columns.split(',')
-            .map(|col| col.trim().to_string())
-            .collect();
-        
+        let requested_cols: Vec<ColumnName> = columns.split(',')
+            .map(|col| ColumnName::parse(col.trim()))
+            .collect::<Result<_, _>>()?;
+
         // Verify all columns exist
-        let cols_query = format!("SHOW COLUMNS FROM `{}`", table);
-        let available_columns: Vec<String> = conn.query(cols_query)
-            .map_err(AppError::DbError)?
-            .into_iter()
-            .map(|row: mysql::Row| {
-                // Extract just the Field column which contains the column name
-                row.get::<String, _>("Field").unwrap_or_default()
-            })
-            .collect();
-        
-        let invalid_columns: Vec<&String> = requested_cols.iter()
-            .filter(|col| !available_columns.contains(col))
-            .collect();
-        
-        if !invalid_columns.is_empty() {
-            return Err(AppError::BadRequest(format!(
-                "Invalid columns requested: {:?}", 
-                invalid_columns
-            )));
+        for col in &requested_cols {
+            if !column_exists(&mut conn, &table, col).await? {
+                return Err(AppError::BadRequest(format!(
+                    "Invalid column requested: '{}'", col.as_str()
+                )));
+            }
         }
-        
+
         columns_sql = requested_cols.iter()
-            .map(|col| format!("`{}`", col))
+            .map(|col| quote_ident(&col.0))
             .collect::<Vec<String>>()
             .join(", ");
-        
+
         columns_json = serde_json::Value::Array(
             requested_cols.into_iter()
-                .map(|c| serde_json::Value::String(c))
+                .map(|c| serde_json::Value::String(c.as_str().to_string()))
                 .collect()
         );
     } else {
         columns_sql = "*".to_string();
         columns_json = serde_json::Value::String("all".to_string());
     }
-    
-    // Execute the main query
-    let query = format!(
-        "SELECT {} FROM `{}` WHERE `{}` = ? LIMIT {}",
-        columns_sql, table, field, limit
+
+    // Total count of matching rows, ignoring LIMIT/OFFSET/ORDER BY, so
+    // clients can build paginated UIs
+    let count_query = format!(
+        "SELECT COUNT(*) AS count FROM {} WHERE {} {} ?",
+        quote_ident(&table.0), quote_ident(&field.0), operator
     );
-    
-    let prepared = conn.prep(query)
+    let count_rows: Vec<mysql_async::Row> = conn.exec(&count_query, (value.clone(),)).await
         .map_err(AppError::DbError)?;
-    
-    let rows = conn.exec_iter(prepared, (value.clone(),))
+    let total_count = rows_to::<(u64,)>(count_rows)?.first().map(|r| r.0).unwrap_or(0);
+
+    // Execute the main query. `limit`/`offset` are bound as params rather
+    // than baked into the SQL text so the query shape stays stable across
+    // pages of the same query instead of varying the literal text per
+    // offset. mysql_async prepares and caches the statement itself, per
+    // connection, so there's no need to prepare it ourselves here.
+    let query = format!(
+        "SELECT {} FROM {} WHERE {} {} ?{} LIMIT ? OFFSET ?",
+        columns_sql, quote_ident(&table.0), quote_ident(&field.0), operator, order_clause
+    );
+
+    let rows: Vec<mysql_async::Row> = conn.exec(&query, (value.clone(), limit, offset)).await
         .map_err(AppError::DbError)?;
-    
-    // Convert results to Vec<HashMap<String, Value>>
-    let results = rows.into_iter().map(|row_result| {
-        let row = row_result.unwrap();
-        let mut map = HashMap::new();
-        
-        for (i, column) in row.columns_ref().iter().enumerate() {
-            let column_name = column.name_str().to_string();
-            
-            // Handle different types of values
-            let value: serde_json::Value = match row.get_opt::<mysql::Value, _>(i) {
-                Some(Ok(mysql::Value::NULL)) => serde_json::Value::Null,
-                Some(Ok(mysql::Value::Bytes(bytes))) => {
-                    if let Ok(s) = String::from_utf8(bytes.clone()) {
-                        serde_json::Value::String(s)
-                    } else {
-                        serde_json::Value::Array(
-                            bytes.into_iter()
-                                .map(|b| serde_json::Value::Number(b.into()))
-                                .collect()
-                        )
-                    }
-                },
-                Some(Ok(mysql::Value::Int(i))) => serde_json::Value::Number(i.into()),
-                Some(Ok(mysql::Value::UInt(i))) => {
-                    if let Some(num) = serde_json::Number::from_u128(i as u128) {
-                        serde_json::Value::Number(num)
-                    } else {
-                        serde_json::Value::String(i.to_string())
-                    }
-                },
-                Some(Ok(mysql::Value::Float(f))) => {
-                    if let Some(num) = serde_json::Number::from_f64(f.into()) {
-                        serde_json::Value::Number(num)
-                    } else {
-                        serde_json::Value::String(f.to_string())
-                    }
-                },
-                Some(Ok(mysql::Value::Date(..))) | 
-                Some(Ok(mysql::Value::Time(..))) => {
-                    // Convert dates to strings
-                    let s: Option<String> = row.get(i);
-                    serde_json::Value::String(s.unwrap_or_default())
-                },
-                _ => serde_json::Value::Null,
-            };
-            
-            map.insert(column_name, value);
-        }
-        
-        map
-    }).collect();
-    
+
+    let results = rows_to::<HashMap<String, serde_json::Value>>(rows)?;
+
     Ok(HttpResponse::Ok().json(QueryResponse {
-        table,
-        field,
+        table: table.as_str().to_string(),
+        field: field.as_str().to_string(),
         value,
         columns: columns_json,
         limit,
+        offset,
+        total_count,
         results,
     }))
 }
 
+// Column `Type` prefixes (from `SHOW COLUMNS`) that are safe to compare
+// against an arbitrary string value without tripping a type mismatch.
+const TEXT_COLUMN_TYPE_PREFIXES: &[&str] = &[
+    "char", "varchar", "text", "tinytext", "mediumtext", "longtext", "enum", "set",
+];
+
+// Default rows returned per matching (table, column) pair (a caller's
+// `limit` may raise it, up to 1000), and how many (table, column) pairs are
+// scanned concurrently so a schema with hundreds of tables can't exhaust
+// the connection pool.
+const SEARCH_MATCHES_PER_PAIR: u32 = 20;
+const SEARCH_CONCURRENCY: usize = 16;
+
+// Fan out a `SELECT ... WHERE col = ? LIMIT n` across every (table, column)
+// pair in the schema and fan the results back in. Tables are enumerated via
+// `SHOW TABLES`, textual columns via `SHOW COLUMNS`, and each pair checks
+// out its own connection so the scan runs with bounded concurrency instead
+// of serializing behind a single connection.
+async fn search_value(
+    data: web::Data<AppState>,
+    query: web::Query<SearchParams>,
+) -> Result<impl Responder, AppError> {
+    let value = query.value.clone();
+    let limit = std::cmp::min(query.limit.unwrap_or(SEARCH_MATCHES_PER_PAIR), 1000);
+
+    let mut conn = get_conn(&data).await?;
+    let tables: Vec<String> = conn.query("SHOW TABLES").await
+        .map_err(AppError::DbError)?;
+
+    let mut pairs: Vec<(TableName, ColumnName)> = Vec::new();
+    for table in &tables {
+        let table = match TableName::parse(table) {
+            Ok(table) => table,
+            Err(err) => {
+                log::warn!("Search: skipping unparseable table name '{}': {}", table, err);
+                continue;
+            }
+        };
+
+        let columns_query = format!("SHOW COLUMNS FROM {}", quote_ident(&table.0));
+        let columns: Vec<mysql_async::Row> = conn.query(columns_query).await
+            .map_err(AppError::DbError)?;
+
+        for row in columns {
+            let field: String = row.get("Field").unwrap_or_default();
+            let col_type: String = row.get("Type").unwrap_or_default();
+            let col_type = col_type.to_ascii_lowercase();
+
+            if !TEXT_COLUMN_TYPE_PREFIXES.iter().any(|prefix| col_type.starts_with(prefix)) {
+                continue;
+            }
+
+            match ColumnName::parse(&field) {
+                Ok(column) => pairs.push((table.clone(), column)),
+                Err(err) => log::warn!("Search: skipping unparseable column name '{}': {}", field, err),
+            }
+        }
+    }
+    drop(conn);
+
+    let pool = data.db_pool.clone();
+    let results: Vec<SearchMatch> = stream::iter(pairs.into_iter().map(|(table, column)| {
+        let pool = pool.clone();
+        let value = value.clone();
+        async move {
+            let mut conn = match pool.get_conn().await {
+                Ok(conn) => conn,
+                Err(err) => {
+                    log::warn!("Search: failed to get connection for {}.{}: {}", table.as_str(), column.as_str(), err);
+                    return None;
+                }
+            };
+
+            let query = format!(
+                "SELECT * FROM {} WHERE {} = ? LIMIT {}",
+                quote_ident(&table.0), quote_ident(&column.0), limit
+            );
+            let rows: Vec<mysql_async::Row> = match conn.exec(query, (value,)).await {
+                Ok(rows) => rows,
+                Err(err) => {
+                    log::warn!("Search: query against {}.{} failed: {}", table.as_str(), column.as_str(), err);
+                    return None;
+                }
+            };
+
+            if rows.is_empty() {
+                return None;
+            }
+
+            let matches = match rows_to::<HashMap<String, serde_json::Value>>(rows) {
+                Ok(matches) => matches,
+                Err(err) => {
+                    log::warn!("Search: row conversion failed for {}.{}: {}", table.as_str(), column.as_str(), err);
+                    return None;
+                }
+            };
+
+            Some(SearchMatch {
+                table: table.as_str().to_string(),
+                column: column.as_str().to_string(),
+                matches,
+            })
+        }
+    }))
+    .buffer_unordered(SEARCH_CONCURRENCY)
+    .filter_map(|result| async move { result })
+    .collect()
+    .await;
+
+    Ok(HttpResponse::Ok().json(SearchResponse { value, results }))
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     // Initialize logger
     env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
-    
+
     // Load configuration
     let settings = config::Settings::new()
         .expect("Failed to load configuration");
-    
+
     // Create database connection pool
-    let opts = mysql::OptsBuilder::new()
-        .ip_or_hostname(Some(&settings.mysql_host))
+    let opts = mysql_async::OptsBuilder::default()
+        .ip_or_hostname(&settings.mysql_host)
         .tcp_port(settings.mysql_port)
         .user(Some(&settings.mysql_user))
         .pass(Some(&settings.mysql_password))
-        .db_name(Some(&settings.mysql_db));
-    
-    let pool = mysql::Pool::new(opts)
-        .expect("Failed to create database connection pool");
-    
-    // Test the connection
-    let mut conn = pool.get_conn()
+        .db_name(Some(&settings.mysql_db))
+        .stmt_cache_size(settings.stmt_cache_capacity);
+
+    let pool = mysql_async::Pool::new(opts);
+
+    // Test the connection, tolerating a MySQL server that isn't up yet
+    let mut conn = acquire_conn(&pool, settings.db_retry_delay_secs, settings.db_connect_timeout_secs).await
         .expect("Failed to connect to database");
-    
+
     // Check connection by executing a simple query
-    conn.query_drop("SELECT 1")
+    conn.query_drop("SELECT 1").await
         .expect("Database connection test failed");
-    
+
     log::info!("Successfully connected to database");
-    
+
     // Create application state
     let state = web::Data::new(AppState {
-        db_pool: Mutex::new(pool),
+        db_pool: pool,
     });
-    
+
     // Start the HTTP server
     log::info!("Starting server at http://0.0.0.0:{}", settings.app_port);
     HttpServer::new(move || {
         App::new()
             .app_data(state.clone())
             // Define routes
+            .route("/health", web::get().to(health_check))
             .route("/tables", web::get().to(list_tables))
             .route("/tables/{table}/columns", web::get().to(table_columns))
             .route("/tables/{table}/columns/{column}/values", web::get().to(column_distinct_values))
             .route("/tables/{table}/count", web::get().to(table_row_count))
             .route("/query/{table}", web::get().to(query_table))
+            .route("/search", web::get().to(search_value))
     })
     .bind(("0.0.0.0", settings.app_port))?
     .run()
     .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifier_accepts_letters_digits_underscore_and_dollar() {
+        assert!(Identifier::parse("users").is_ok());
+        assert!(Identifier::parse("_hidden123").is_ok());
+        assert!(Identifier::parse("col$1").is_ok());
+    }
+
+    #[test]
+    fn identifier_rejects_empty_and_overlong_input() {
+        assert!(Identifier::parse("").is_err());
+        assert!(Identifier::parse(&"a".repeat(MAX_IDENTIFIER_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn identifier_rejects_sql_metacharacters() {
+        assert!(Identifier::parse("a; DROP TABLE users").is_err());
+        assert!(Identifier::parse("`users`").is_err());
+        assert!(Identifier::parse("users--").is_err());
+        assert!(Identifier::parse("users' OR '1'='1").is_err());
+    }
+
+    #[test]
+    fn quote_ident_backtick_quotes_the_identifier() {
+        let ident = Identifier::parse("users").unwrap();
+        assert_eq!(quote_ident(&ident), "`users`");
+    }
+
+    #[test]
+    fn resolve_operator_maps_each_whitelisted_name() {
+        assert_eq!(resolve_operator("eq").unwrap(), "=");
+        assert_eq!(resolve_operator("ne").unwrap(), "!=");
+        assert_eq!(resolve_operator("lt").unwrap(), "<");
+        assert_eq!(resolve_operator("lte").unwrap(), "<=");
+        assert_eq!(resolve_operator("gt").unwrap(), ">");
+        assert_eq!(resolve_operator("gte").unwrap(), ">=");
+        assert_eq!(resolve_operator("like").unwrap(), "LIKE");
+    }
+
+    #[test]
+    fn resolve_operator_rejects_anything_outside_the_whitelist() {
+        assert!(resolve_operator("=").is_err());
+        assert!(resolve_operator("OR 1=1").is_err());
+        assert!(resolve_operator("EQ").is_err());
+        assert!(resolve_operator("").is_err());
+    }
+
+    #[test]
+    fn resolve_order_dir_accepts_asc_and_desc_case_insensitively() {
+        assert_eq!(resolve_order_dir("asc").unwrap(), "ASC");
+        assert_eq!(resolve_order_dir("ASC").unwrap(), "ASC");
+        assert_eq!(resolve_order_dir("desc").unwrap(), "DESC");
+        assert_eq!(resolve_order_dir("DESC").unwrap(), "DESC");
+    }
+
+    #[test]
+    fn resolve_order_dir_rejects_anything_else() {
+        assert!(resolve_order_dir("ascending").is_err());
+        assert!(resolve_order_dir("; DROP TABLE users").is_err());
+        assert!(resolve_order_dir("").is_err());
+    }
+}